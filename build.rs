@@ -0,0 +1,6 @@
+fn main() {
+    if std::env::var_os("CARGO_FEATURE_LIBWACOM").is_some() {
+        pkg_config::probe_library("libwacom")
+            .expect("libwacom feature enabled but libwacom development files were not found via pkg-config");
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{ffi, AsRaw, Device, FromRaw};
+
+use super::TabletPadModeGroup;
+
+impl Device {
+    /// Return the number of buttons on a device with the `DeviceCapability::TabletPad`
+    /// capability, or `-1` if the device does not have that capability.
+    pub fn tablet_pad_num_buttons(&self) -> i32 {
+        unsafe { ffi::libinput_device_tablet_pad_get_num_buttons(self.as_raw_mut()) }
+    }
+
+    /// Return the number of rings on a device with the `DeviceCapability::TabletPad`
+    /// capability, or `-1` if the device does not have that capability.
+    pub fn tablet_pad_num_rings(&self) -> i32 {
+        unsafe { ffi::libinput_device_tablet_pad_get_num_rings(self.as_raw_mut()) }
+    }
+
+    /// Return the number of strips on a device with the `DeviceCapability::TabletPad`
+    /// capability, or `-1` if the device does not have that capability.
+    pub fn tablet_pad_num_strips(&self) -> i32 {
+        unsafe { ffi::libinput_device_tablet_pad_get_num_strips(self.as_raw_mut()) }
+    }
+
+    /// Return the number of mode groups on a device with the `DeviceCapability::TabletPad`
+    /// capability, or `-1` if the device does not have that capability.
+    ///
+    /// Each mode group is indexed from 0, see [`tablet_pad_mode_group`](Device::tablet_pad_mode_group).
+    pub fn tablet_pad_num_mode_groups(&self) -> i32 {
+        unsafe { ffi::libinput_device_tablet_pad_get_num_mode_groups(self.as_raw_mut()) }
+    }
+
+    /// Return the mode group at `index`, or `None` if `index` is not less than
+    /// [`tablet_pad_num_mode_groups`](Device::tablet_pad_num_mode_groups).
+    pub fn tablet_pad_mode_group(&self, index: u32) -> Option<TabletPadModeGroup> {
+        unsafe {
+            let group = ffi::libinput_device_tablet_pad_get_mode_group(self.as_raw_mut(), index);
+            if group.is_null() {
+                None
+            } else {
+                Some(TabletPadModeGroup::from_raw(group))
+            }
+        }
+    }
+}
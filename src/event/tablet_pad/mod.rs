@@ -0,0 +1,4 @@
+mod device;
+mod mode_group;
+
+pub use self::mode_group::*;
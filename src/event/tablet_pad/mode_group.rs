@@ -0,0 +1,56 @@
+use crate::{ffi, AsRaw, FromRaw};
+
+ffi_ref_struct! {
+    /// A mode group on a device with the `DeviceCapability::TabletPad` capability.
+    ///
+    /// A mode group is a set of buttons, rings and strips that share a common mode. Changing
+    /// the mode of one item within a mode group (e.g. by pressing a mode-toggle button) changes
+    /// the mode of all other items in the same group, see
+    /// [Tablet pad modes](https://wayland.freedesktop.org/libinput/doc/latest/tablet-pad-support.html)
+    /// for details.
+    ///
+    /// A device's mode groups are obtained through `Device::tablet_pad_mode_group`, and its
+    /// number of mode groups, buttons, rings and strips through `Device::tablet_pad_num_mode_groups`,
+    /// `Device::tablet_pad_num_buttons`, `Device::tablet_pad_num_rings` and
+    /// `Device::tablet_pad_num_strips` respectively.
+    struct TabletPadModeGroup, ffi::libinput_tablet_pad_mode_group, ffi::libinput_tablet_pad_mode_group_ref, ffi::libinput_tablet_pad_mode_group_unref
+}
+
+impl TabletPadModeGroup {
+    ffi_func!(
+    /// Return the index of this mode group amongst all mode groups on the associated device.
+    pub fn index, ffi::libinput_tablet_pad_mode_group_get_index, u32);
+    ffi_func!(
+    /// Return the current mode this mode group is in.
+    ///
+    /// The initial mode is always 0.
+    pub fn mode, ffi::libinput_tablet_pad_mode_group_get_mode, u32);
+    ffi_func!(
+    /// Return the number of modes this mode group supports.
+    pub fn num_modes, ffi::libinput_tablet_pad_mode_group_get_num_modes, u32);
+
+    /// Check if a button is part of this mode group.
+    pub fn has_button(&self, button: u32) -> bool {
+        unsafe { ffi::libinput_tablet_pad_mode_group_has_button(self.as_raw_mut(), button) != 0 }
+    }
+
+    /// Check if a ring is part of this mode group.
+    pub fn has_ring(&self, ring: u32) -> bool {
+        unsafe { ffi::libinput_tablet_pad_mode_group_has_ring(self.as_raw_mut(), ring) != 0 }
+    }
+
+    /// Check if a strip is part of this mode group.
+    pub fn has_strip(&self, strip: u32) -> bool {
+        unsafe { ffi::libinput_tablet_pad_mode_group_has_strip(self.as_raw_mut(), strip) != 0 }
+    }
+
+    /// Check if the button is a toggle button for this mode group.
+    ///
+    /// Pressing a toggle button switches the mode group to the next mode, wrapping around to
+    /// 0 once the last mode is reached.
+    pub fn button_is_toggle(&self, button: u32) -> bool {
+        unsafe {
+            ffi::libinput_tablet_pad_mode_group_button_is_toggle(self.as_raw_mut(), button) != 0
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod tool;
+#[cfg(feature = "libwacom")]
+mod wacom;
+
+pub use self::tool::*;
+#[cfg(feature = "libwacom")]
+pub use self::wacom::*;
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
 use crate::{ffi, AsRaw, FromRaw};
 
 /// Available tool types for a device with the `DeviceCapability::TabletTool` capability.
@@ -19,6 +22,7 @@ use crate::{ffi, AsRaw, FromRaw};
 /// putting a Wacom stroke nib into a classic pen leaves the tool type as
 /// `TabletToolType::Pen`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum TabletToolType {
     /// A generic pen.
@@ -151,3 +155,170 @@ impl TabletTool {
     /// for all values of major.
     pub fn tablet_tool_has_size, ffi::libinput_tablet_tool_has_size, bool);
 }
+
+impl PartialEq for TabletTool {
+    /// Two tools with [`is_unique`](TabletTool::is_unique) set are equal when their
+    /// `(serial, tool_id, tool_type)` triple matches, regardless of which `Device` or
+    /// `TabletTool` value produced them. Otherwise, since libinput hands out one shared tool
+    /// per type per tablet in that case, equality falls back to the underlying pointer so
+    /// distinct non-unique tools never collide.
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_unique() && other.is_unique() {
+            self.serial() == other.serial()
+                && self.tool_id() == other.tool_id()
+                && self.tool_type() == other.tool_type()
+        } else {
+            std::ptr::eq(self.as_raw(), other.as_raw())
+        }
+    }
+}
+
+impl Eq for TabletTool {}
+
+impl Hash for TabletTool {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.is_unique() {
+            self.serial().hash(state);
+            self.tool_id().hash(state);
+            self.tool_type().hash(state);
+        } else {
+            (self.as_raw() as *const _ as usize).hash(state);
+        }
+    }
+}
+
+/// Resolves each `TabletTool` seen in a `TabletToolProximityEvent` to a single canonical
+/// instance, using `TabletTool`'s tracking-identity `PartialEq` impl.
+///
+/// Without this, code that keys per-tool state off of a `TabletTool` would treat every
+/// proximity-in as a new tool, even when it is the same physical stylus coming back from a
+/// proximity-out. Pass every tool through [`track`](ToolTracker::track) and key state off of
+/// the value it returns instead.
+#[derive(Debug, Default)]
+pub struct ToolTracker {
+    tools: HashMap<(u64, u64, Option<TabletToolType>), TabletTool>,
+}
+
+impl ToolTracker {
+    /// Create a new, empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a tool seen in a proximity event.
+    ///
+    /// Tools that are not [`is_unique`](TabletTool::is_unique) cannot be tracked reliably, as
+    /// libinput only synthesizes one such tool per type per tablet, and are returned unchanged.
+    /// For unique tools, this returns the `TabletTool` previously seen for the same
+    /// `(serial, tool_id, tool_type)` triple, if any, otherwise it stores and returns `tool`.
+    /// This matches `TabletTool`'s own `PartialEq`/`Hash` impl, so a tool handed back by
+    /// `track` is always `==` the tool that was passed in.
+    pub fn track(&mut self, tool: TabletTool) -> TabletTool {
+        if !tool.is_unique() {
+            return tool;
+        }
+
+        let key = (tool.serial(), tool.tool_id(), tool.tool_type());
+        self.tools.entry(key).or_insert(tool).clone()
+    }
+
+    /// Remove all tracked tools.
+    pub fn clear(&mut self) {
+        self.tools.clear();
+    }
+}
+
+/// A snapshot of a [`TabletTool`]'s identity and axis capabilities, bundled into a single
+/// `Copy` value instead of the individual `has_*` queries on `TabletTool`.
+///
+/// `serial`, `tool_id` and `tool_type` carry the same tracking identity used by `TabletTool`'s
+/// `PartialEq` impl for unique tools, so a stored `TabletToolCapabilities` can be matched back up against whatever tool is
+/// reported on a later proximity-in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TabletToolCapabilities {
+    serial: u64,
+    tool_id: u64,
+    tool_type: Option<TabletToolType>,
+    has_distance: bool,
+    has_pressure: bool,
+    has_rotation: bool,
+    has_slider: bool,
+    has_tilt: bool,
+    has_wheel: bool,
+    #[cfg(feature = "libinput_1_14")]
+    has_size: bool,
+}
+
+impl TabletToolCapabilities {
+    /// Return the serial number of the tool, see [`TabletTool::serial`].
+    pub fn serial(&self) -> u64 {
+        self.serial
+    }
+
+    /// Return the tool ID of the tool, see [`TabletTool::tool_id`].
+    pub fn tool_id(&self) -> u64 {
+        self.tool_id
+    }
+
+    /// Return the tool type of the tool, see [`TabletTool::tool_type`].
+    pub fn tool_type(&self) -> Option<TabletToolType> {
+        self.tool_type
+    }
+
+    /// Return whether the tool supports distance, see [`TabletTool::has_distance`].
+    pub fn has_distance(&self) -> bool {
+        self.has_distance
+    }
+
+    /// Return whether the tool supports pressure, see [`TabletTool::has_pressure`].
+    pub fn has_pressure(&self) -> bool {
+        self.has_pressure
+    }
+
+    /// Return whether the tool supports z-rotation, see [`TabletTool::has_rotation`].
+    pub fn has_rotation(&self) -> bool {
+        self.has_rotation
+    }
+
+    /// Return whether the tool has a slider axis, see [`TabletTool::has_slider`].
+    pub fn has_slider(&self) -> bool {
+        self.has_slider
+    }
+
+    /// Return whether the tool supports tilt, see [`TabletTool::has_tilt`].
+    pub fn has_tilt(&self) -> bool {
+        self.has_tilt
+    }
+
+    /// Return whether the tool has a relative wheel, see [`TabletTool::has_wheel`].
+    pub fn has_wheel(&self) -> bool {
+        self.has_wheel
+    }
+
+    /// Return whether the tool has an ellipsis major and minor, see
+    /// [`TabletTool::tablet_tool_has_size`].
+    #[cfg(feature = "libinput_1_14")]
+    pub fn has_size(&self) -> bool {
+        self.has_size
+    }
+}
+
+impl TabletTool {
+    /// Build a serializable snapshot of this tool's identity and axis capabilities.
+    pub fn capabilities(&self) -> TabletToolCapabilities {
+        TabletToolCapabilities {
+            serial: self.serial(),
+            tool_id: self.tool_id(),
+            tool_type: self.tool_type(),
+            has_distance: self.has_distance(),
+            has_pressure: self.has_pressure(),
+            has_rotation: self.has_rotation(),
+            has_slider: self.has_slider(),
+            has_tilt: self.has_tilt(),
+            has_wheel: self.has_wheel(),
+            #[cfg(feature = "libinput_1_14")]
+            has_size: self.tablet_tool_has_size(),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Stylus model lookup backed by [libwacom](https://github.com/linuxwacom/libwacom).
+//!
+//! A [`TabletTool`]'s [`tool_id`](TabletTool::tool_id) is a vendor-specific number; this module
+//! resolves it against libwacom's stylus database so a tool can be shown to a user by its model
+//! name (e.g. `"Wacom Pro Pen 2"`) rather than that raw ID.
+
+use std::convert::TryFrom;
+use std::ffi::CStr;
+use std::os::raw::c_int;
+use std::sync::OnceLock;
+
+use super::TabletTool;
+
+#[allow(non_camel_case_types, dead_code)]
+mod ffi {
+    use std::os::raw::{c_char, c_int};
+
+    #[repr(C)]
+    pub struct WacomDeviceDatabase {
+        _private: [u8; 0],
+    }
+
+    #[repr(C)]
+    pub struct WacomStylus {
+        _private: [u8; 0],
+    }
+
+    extern "C" {
+        pub fn libwacom_database_new() -> *mut WacomDeviceDatabase;
+        pub fn libwacom_database_destroy(db: *mut WacomDeviceDatabase);
+        pub fn libwacom_stylus_get_for_id(
+            db: *mut WacomDeviceDatabase,
+            id: c_int,
+        ) -> *const WacomStylus;
+        pub fn libwacom_stylus_get_name(stylus: *const WacomStylus) -> *const c_char;
+        pub fn libwacom_stylus_get_type(stylus: *const WacomStylus) -> c_int;
+        pub fn libwacom_stylus_is_eraser(stylus: *const WacomStylus) -> c_int;
+    }
+}
+
+/// A libwacom database handle, opened once and kept for the lifetime of the process.
+///
+/// libwacom parses its on-disk tablet descriptions when the database is opened, so this is
+/// created lazily on first use and reused by every later [`TabletTool::wacom_stylus`] call
+/// instead of being rebuilt per lookup.
+struct Database(*mut ffi::WacomDeviceDatabase);
+
+// Only ever accessed through shared references to query styluses by ID, which libwacom treats
+// as a read-only operation once the database has been built.
+unsafe impl Send for Database {}
+unsafe impl Sync for Database {}
+
+fn database() -> Option<&'static Database> {
+    static DATABASE: OnceLock<Option<Database>> = OnceLock::new();
+    DATABASE
+        .get_or_init(|| {
+            let raw = unsafe { ffi::libwacom_database_new() };
+            if raw.is_null() {
+                None
+            } else {
+                Some(Database(raw))
+            }
+        })
+        .as_ref()
+}
+
+/// The physical category of a [`WacomStylus`], as classified by libwacom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WacomStylusType {
+    /// libwacom does not know this stylus' type.
+    Unknown,
+    /// A generic stylus.
+    General,
+    /// An inking pen.
+    Inking,
+    /// An airbrush-style stylus.
+    Airbrush,
+    /// A marker/felt-tip-style stylus.
+    Marker,
+    /// A stroke pen.
+    Stroke,
+    /// A puck-like tool.
+    Puck,
+    /// A 3D stylus.
+    ThreeD,
+    /// A stylus used with a mobile device.
+    Mobile,
+    /// A spring-loaded stylus.
+    Spring,
+}
+
+impl WacomStylusType {
+    /// Map a raw `WacomStylusType` C enum value to its safe Rust representation, falling back
+    /// to `Unknown` for any discriminant this crate doesn't recognize yet (e.g. a newer
+    /// libwacom adding a type).
+    fn from_raw(raw: c_int) -> Self {
+        match raw {
+            0 => WacomStylusType::Unknown,
+            1 => WacomStylusType::General,
+            2 => WacomStylusType::Inking,
+            3 => WacomStylusType::Airbrush,
+            4 => WacomStylusType::Marker,
+            5 => WacomStylusType::Stroke,
+            6 => WacomStylusType::Puck,
+            7 => WacomStylusType::ThreeD,
+            8 => WacomStylusType::Mobile,
+            9 => WacomStylusType::Spring,
+            _x => {
+                #[cfg(feature = "log")]
+                log::warn!("Unknown WacomStylusType returned by libwacom: {}", _x);
+                WacomStylusType::Unknown
+            }
+        }
+    }
+}
+
+/// Model metadata for a stylus, as resolved by libwacom from a [`TabletTool`]'s
+/// [`tool_id`](TabletTool::tool_id).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WacomStylus {
+    name: String,
+    stylus_type: WacomStylusType,
+    is_eraser: bool,
+}
+
+impl WacomStylus {
+    /// The human-readable model name, e.g. `"Wacom Pro Pen 2"`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The libwacom stylus type, e.g. puck, general or marker.
+    pub fn stylus_type(&self) -> WacomStylusType {
+        self.stylus_type
+    }
+
+    /// Whether this stylus reports as an eraser.
+    pub fn is_eraser(&self) -> bool {
+        self.is_eraser
+    }
+}
+
+impl TabletTool {
+    /// Look up this tool's model in the libwacom stylus database.
+    ///
+    /// Returns `None` if the tool does not report a tool ID, if the tool ID is not known to
+    /// libwacom, or if libwacom's database could not be loaded.
+    pub fn wacom_stylus(&self) -> Option<WacomStylus> {
+        let tool_id = self.tool_id();
+        if tool_id == 0 {
+            return None;
+        }
+        let tool_id = c_int::try_from(tool_id).ok()?;
+
+        let db = database()?;
+
+        unsafe {
+            let stylus = ffi::libwacom_stylus_get_for_id(db.0, tool_id);
+            if stylus.is_null() {
+                return None;
+            }
+
+            let name = CStr::from_ptr(ffi::libwacom_stylus_get_name(stylus))
+                .to_string_lossy()
+                .into_owned();
+            let stylus_type = WacomStylusType::from_raw(ffi::libwacom_stylus_get_type(stylus));
+            let is_eraser = ffi::libwacom_stylus_is_eraser(stylus) != 0;
+
+            Some(WacomStylus {
+                name,
+                stylus_type,
+                is_eraser,
+            })
+        }
+    }
+}